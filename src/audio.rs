@@ -0,0 +1,55 @@
+use wasm_bindgen::JsValue;
+use web_sys::{AudioContext, OscillatorType};
+
+pub struct Sequencer {
+    context: AudioContext,
+}
+
+impl Sequencer {
+    // Must be constructed from a user gesture -- browsers refuse to start
+    // an AudioContext otherwise.
+    pub fn new() -> Result<Self, JsValue> {
+        Ok(Self {
+            context: AudioContext::new()?,
+        })
+    }
+
+    pub fn trigger_note(&self, freq_hz: f64, duration_s: f64) -> Result<(), JsValue> {
+        let ctx = &self.context;
+        let now = ctx.current_time();
+
+        let osc = ctx.create_oscillator()?;
+        osc.set_type(OscillatorType::Sine);
+        osc.frequency().set_value(freq_hz as f32);
+
+        let gain = ctx.create_gain()?;
+        gain.gain().set_value_at_time(0.0001, now)?;
+        gain.gain().exponential_ramp_to_value_at_time(0.3, now + 0.01)?;
+        gain.gain()
+            .exponential_ramp_to_value_at_time(0.0001, now + duration_s)?;
+
+        osc.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&ctx.destination())?;
+
+        osc.start()?;
+        osc.stop_with_when(now + duration_s)?;
+        Ok(())
+    }
+}
+
+// Semitones above the tonic of a major pentatonic scale.
+const PENTATONIC_STEPS: [i32; 5] = [0, 2, 4, 7, 9];
+const BASE_MIDI_NOTE: i32 = 48; // C3
+
+pub fn row_to_freq(row: usize, total_rows: usize) -> f64 {
+    const MAX_OCTAVES: i32 = 4;
+    let inverted_row = total_rows.saturating_sub(1).saturating_sub(row);
+    let degree = inverted_row % PENTATONIC_STEPS.len();
+    let octave = ((inverted_row / PENTATONIC_STEPS.len()) as i32) % MAX_OCTAVES;
+    let semitone = BASE_MIDI_NOTE + PENTATONIC_STEPS[degree] + octave * 12;
+    midi_to_freq(semitone)
+}
+
+fn midi_to_freq(midi_note: i32) -> f64 {
+    440.0 * 2f64.powf((midi_note as f64 - 69.0) / 12.0)
+}
@@ -0,0 +1,52 @@
+pub const MIN_ZOOM: f64 = 0.2;
+pub const MAX_ZOOM: f64 = 6.0;
+
+// offset_row/offset_col is the board cell at the viewport's top-left corner;
+// zoom scales how many on-screen pixels each cell occupies.
+#[derive(Clone, Copy)]
+pub struct Camera {
+    pub offset_row: f64,
+    pub offset_col: f64,
+    pub zoom: f64,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            offset_row: 0.0,
+            offset_col: 0.0,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl Camera {
+    pub fn pan(&mut self, d_row: f64, d_col: f64) {
+        self.offset_row += d_row;
+        self.offset_col += d_col;
+    }
+
+    pub fn zoom_by(&mut self, factor: f64) {
+        self.zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    pub fn visible_cells(&self, display_px: f64, base_cell_px: f64) -> usize {
+        ((display_px / (base_cell_px * self.zoom)).round() as usize).max(1)
+    }
+
+    pub fn to_board(&self, visible_row: usize, visible_col: usize) -> (isize, isize) {
+        (
+            self.offset_row.floor() as isize + visible_row as isize,
+            self.offset_col.floor() as isize + visible_col as isize,
+        )
+    }
+
+    // cell_px_x/cell_px_y are the actual on-screen pixels per cell, measured
+    // by the caller (e.g. via getBoundingClientRect) rather than assumed,
+    // since CSS can scale the canvas away from its native resolution.
+    pub fn screen_to_board(&self, x_px: f64, y_px: f64, cell_px_x: f64, cell_px_y: f64) -> (isize, isize) {
+        let visible_row = (y_px / cell_px_y).floor().max(0.0) as usize;
+        let visible_col = (x_px / cell_px_x).floor().max(0.0) as usize;
+        self.to_board(visible_row, visible_col)
+    }
+}
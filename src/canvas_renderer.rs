@@ -0,0 +1,53 @@
+use wasm_bindgen::{Clamped, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
+
+const LIVE_RGBA: [u8; 4] = [55, 48, 163, 255]; // tailwind indigo-800
+const DEAD_RGBA: [u8; 4] = [243, 244, 246, 255]; // tailwind gray-100
+const PLAYHEAD_LIVE_RGBA: [u8; 4] = [217, 119, 6, 255]; // tailwind amber-600
+const PLAYHEAD_DEAD_RGBA: [u8; 4] = [254, 243, 199, 255]; // tailwind amber-100
+
+// Draws one pixel per cell; `canvas` is expected to be sized to exactly
+// `width x height` and scaled up for display via CSS.
+pub fn draw(
+    canvas: &HtmlCanvasElement,
+    width: usize,
+    height: usize,
+    is_alive: impl Fn(usize, usize) -> bool,
+    is_playhead_col: impl Fn(usize) -> bool,
+) {
+    let context = match canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|ctx| ctx.dyn_into::<CanvasRenderingContext2d>().ok())
+    {
+        Some(context) => context,
+        None => return,
+    };
+
+    let mut pixels = vec![0u8; width * height * 4];
+    for row in 0..height {
+        for col in 0..width {
+            let alive = is_alive(row, col);
+            let rgba = if is_playhead_col(col) {
+                if alive {
+                    PLAYHEAD_LIVE_RGBA
+                } else {
+                    PLAYHEAD_DEAD_RGBA
+                }
+            } else if alive {
+                LIVE_RGBA
+            } else {
+                DEAD_RGBA
+            };
+            let offset = (row * width + col) * 4;
+            pixels[offset..offset + 4].copy_from_slice(&rgba);
+        }
+    }
+
+    if let Ok(image_data) =
+        ImageData::new_with_u8_clamped_array_and_sh(Clamped(&mut pixels), width as u32, height as u32)
+    {
+        let _ = context.put_image_data(&image_data, 0.0, 0.0);
+    }
+}
@@ -0,0 +1,50 @@
+#[derive(Clone, Copy, PartialEq)]
+pub struct Cellule {
+    state: Life,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Life {
+    Alive,
+    Dead,
+}
+
+impl Default for Life {
+    fn default() -> Self {
+        Life::Dead
+    }
+}
+
+impl Cellule {
+    pub fn new(state: Life) -> Self {
+        Self { state }
+    }
+
+    pub fn new_alive() -> Self {
+        Self::new(Life::Alive)
+    }
+
+    pub fn new_dead() -> Self {
+        Self::new(Life::Dead)
+    }
+
+    pub fn set_alive(&mut self) {
+        self.state = Life::Alive;
+    }
+
+    pub fn set_dead(&mut self) {
+        self.state = Life::Dead;
+    }
+
+    pub fn is_alive(self) -> bool {
+        self.state == Life::Alive
+    }
+
+    pub fn toggle(&mut self) {
+        if self.is_alive() {
+            self.set_dead();
+        } else {
+            self.set_alive();
+        }
+    }
+}
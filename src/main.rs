@@ -1,11 +1,44 @@
+use audio::Sequencer;
+use camera::Camera;
 use cell::Cellule;
 use gloo::timers::callback::Interval;
 use rand::Rng;
+use rule::{Preset, Rule};
+use sparse_engine::SparseLife;
+use web_sys::{window, HtmlCanvasElement, MouseEvent, TouchEvent, WheelEvent};
 use yew::html::Scope;
-use yew::{classes, html, Component, Context, Html};
-use web_sys::window;
+use yew::{classes, html, Component, Context, Html, NodeRef};
 
+mod audio;
+mod camera;
+mod canvas_renderer;
 mod cell;
+mod rule;
+mod sparse_engine;
+
+// On-screen size, in pixels, of one cell at `zoom == 1.0`.
+const BASE_CELL_PX: f64 = 16.0;
+const CANVAS_DISPLAY_PX: f64 = 640.0;
+
+// Floor matches the period MAX_BPM implies (60_000 / 400), so SetSpeed can
+// never push the rate past what the bpm readout is able to display.
+const MIN_TICK_MS: u32 = 150;
+const MAX_TICK_MS: u32 = 1000;
+const SPEED_STEP_MS: u32 = 20;
+const BPM_STEP: u32 = 10;
+const MAX_QUEUED_TICKS: u32 = 20;
+const CATCH_UP_BUDGET_MS: f64 = 16.0;
+const DEFAULT_TICK_MS: u32 = 200;
+const DEFAULT_BPM: u32 = 60_000 / DEFAULT_TICK_MS;
+const MIN_BPM: u32 = 20;
+const MAX_BPM: u32 = 400;
+const NOTE_DURATION_S: f64 = 0.25;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum RenderMode {
+    Dom,
+    Canvas,
+}
 
 pub enum Msg {
     Random,
@@ -14,6 +47,18 @@ pub enum Msg {
     Reset,
     Stop,
     ToggleCellule(usize),
+    ToggleEngine,
+    ToggleRenderMode,
+    ToggleGridLines,
+    PointerDown(f64, f64),
+    PointerMove(f64, f64),
+    PointerUp(f64, f64),
+    CancelDrag,
+    Wheel(f64),
+    SetSpeed(u32),
+    ToggleSound,
+    SetBpm(u32),
+    SetRule(Preset),
     Tick,
 }
 
@@ -22,12 +67,43 @@ pub struct App {
     cellules: Vec<Cellule>,
     cellules_width: usize,
     cellules_height: usize,
+    // Sparse engine runs on an unbounded plane in canvas mode, unlike the
+    // clamped dense grid.
+    sparse: SparseLife,
+    use_sparse_engine: bool,
+    render_mode: RenderMode,
+    canvas_ref: NodeRef,
+    camera: Camera,
+    show_grid_lines: bool,
+    dragging_from: Option<(f64, f64)>,
+    drag_moved: bool,
+    // Interval has no reschedule method, so changing this recreates `_interval`.
+    tick_period_ms: u32,
+    queued_ticks: u32,
+    playhead_col: usize,
+    sound_enabled: bool,
+    bpm: u32,
+    // Lazily created on the first ToggleSound -- AudioContext needs a user gesture.
+    sequencer: Option<Sequencer>,
+    rule: Rule,
+    rule_preset: Preset,
     _interval: Interval,
 }
 
 impl App {
     pub fn random_mutate(&mut self) {
         let mut rng = rand::thread_rng();
+        if self.use_sparse_engine {
+            self.sparse.clear();
+            for row in 0..self.cellules_height as isize {
+                for col in 0..self.cellules_width as isize {
+                    if rng.gen_bool(0.5) {
+                        self.sparse.set_alive((row, col));
+                    }
+                }
+            }
+            return;
+        }
         for cellule in self.cellules.iter_mut() {
             if rng.gen_bool(0.5) {
                 cellule.set_alive();
@@ -38,6 +114,10 @@ impl App {
     }
 
     fn reset(&mut self) {
+        if self.use_sparse_engine {
+            self.sparse.clear();
+            return;
+        }
         for cellule in self.cellules.iter_mut() {
             cellule.set_dead();
         }
@@ -49,13 +129,14 @@ impl App {
         for row in 0..self.cellules_height {
             for col in 0..self.cellules_width {
                 let neighbors = self.neighbors(row as isize, col as isize);
+                let live_neighbors = neighbors.iter().filter(|c| c.is_alive()).count();
 
                 let current_idx = self.row_col_as_idx(row as isize, col as isize);
                 if self.cellules[current_idx].is_alive() {
-                    if Cellule::alone(&neighbors) || Cellule::overpopulated(&neighbors) {
+                    if !self.rule.survives(live_neighbors) {
                         to_dead.push(current_idx);
                     }
-                } else if Cellule::can_be_revived(&neighbors) {
+                } else if self.rule.births(live_neighbors) {
                     to_live.push(current_idx);
                 }
             }
@@ -88,19 +169,122 @@ impl App {
         row * self.cellules_width + col
     }
 
-    fn view_cellule(&self, idx: usize, cellule: &Cellule, link: &Scope<Self>) -> Html {
-        let cellule_status = if cellule.is_alive() {
+    fn idx_as_row_col(&self, idx: usize) -> (isize, isize) {
+        ((idx / self.cellules_width) as isize, (idx % self.cellules_width) as isize)
+    }
+
+    fn step_once(&mut self) {
+        self.sonify_playhead_column();
+        if self.use_sparse_engine {
+            self.sparse.step(&self.rule);
+        } else {
+            self.step();
+        }
+        self.playhead_col = (self.playhead_col + 1) % self.cellules_width;
+    }
+
+    fn sonify_playhead_column(&self) {
+        if !self.sound_enabled {
+            return;
+        }
+        let Some(sequencer) = &self.sequencer else {
+            return;
+        };
+        for row in 0..self.cellules_height {
+            let alive = if self.use_sparse_engine {
+                self.sparse
+                    .is_alive((row as isize, self.playhead_col as isize))
+            } else {
+                self.cellules[row * self.cellules_width + self.playhead_col].is_alive()
+            };
+            if alive {
+                let freq = audio::row_to_freq(row, self.cellules_height);
+                let _ = sequencer.trigger_note(freq, NOTE_DURATION_S);
+            }
+        }
+    }
+
+    fn catch_up(&mut self) {
+        let start = now_ms();
+        while self.queued_ticks > 0 {
+            self.step_once();
+            self.queued_ticks -= 1;
+            if now_ms() - start > CATCH_UP_BUDGET_MS {
+                break;
+            }
+        }
+    }
+
+    fn set_tick_period(&mut self, ctx: &Context<Self>, period_ms: u32) {
+        self.tick_period_ms = period_ms.clamp(MIN_TICK_MS, MAX_TICK_MS);
+        self.bpm = (60_000 / self.tick_period_ms).clamp(MIN_BPM, MAX_BPM);
+        let callback = ctx.link().callback(|_| Msg::Tick);
+        self._interval = Interval::new(self.tick_period_ms, move || callback.emit(()));
+        log::info!("Tick period: {}ms ({} bpm)", self.tick_period_ms, self.bpm);
+    }
+
+    fn visible_viewport(&self) -> (usize, usize) {
+        let display_h_px =
+            CANVAS_DISPLAY_PX * self.cellules_height as f64 / self.cellules_width as f64;
+        (
+            self.camera.visible_cells(CANVAS_DISPLAY_PX, BASE_CELL_PX),
+            self.camera.visible_cells(display_h_px, BASE_CELL_PX),
+        )
+    }
+
+    // Actual on-screen pixels per cell, measured from the canvas's rendered
+    // size rather than assumed from BASE_CELL_PX: the canvas is styled
+    // `width: 100%; max-width: 640px`, so it only renders at CANVAS_DISPLAY_PX
+    // when its container is wide enough, e.g. not on most phones.
+    fn canvas_cell_px(&self) -> Option<(f64, f64)> {
+        let canvas = self.canvas_ref.cast::<HtmlCanvasElement>()?;
+        let rect = canvas.get_bounding_client_rect();
+        let (visible_cols, visible_rows) = self.visible_viewport();
+        Some((rect.width() / visible_cols as f64, rect.height() / visible_rows as f64))
+    }
+
+    // Bounded in DOM mode, which only ever renders the fixed grid window;
+    // unbounded in Canvas mode, where pan/zoom can reach past it.
+    fn sparse_bounds(&self) -> Option<(isize, isize)> {
+        match self.render_mode {
+            RenderMode::Dom => Some((self.cellules_width as isize, self.cellules_height as isize)),
+            RenderMode::Canvas => None,
+        }
+    }
+
+    fn toggle_at(&mut self, row: isize, col: isize) {
+        if self.use_sparse_engine {
+            self.sparse.toggle((row, col));
+        } else {
+            let idx = self.row_col_as_idx(row, col);
+            self.cellules[idx].toggle();
+        }
+    }
+
+    fn view_cellule_at(&self, idx: usize, alive: bool, is_playhead_col: bool, link: &Scope<Self>) -> Html {
+        let cellule_status = if is_playhead_col {
+            if alive {
+                "bg-amber-600 hover:bg-amber-500"
+            } else {
+                "bg-amber-100 hover:bg-amber-200"
+            }
+        } else if alive {
             "bg-indigo-800 hover:bg-indigo-600"
         } else {
             "bg-gray-100 hover:bg-gray-300"
         };
-        
+        let border = if self.show_grid_lines {
+            "border-[0.5px]"
+        } else {
+            "border-0"
+        };
+
         html! {
-            <div 
-                key={idx} 
+            <div
+                key={idx}
                 class={classes!(
                     "w-3", "h-3", "sm:w-4", "sm:h-4", "md:w-5", "md:h-5",
-                    "inline-block", "border-[0.5px]", "border-gray-300", "transition-colors", "duration-200", cellule_status
+                    "inline-block", border, "border-gray-300", "transition-colors", "duration-200", cellule_status
                 )}
                 onclick={link.callback(move |_| Msg::ToggleCellule(idx))}>
             </div>
@@ -114,7 +298,7 @@ impl Component for App {
 
     fn create(ctx: &Context<Self>) -> Self {
         let callback = ctx.link().callback(|_| Msg::Tick);
-        let interval = Interval::new(200, move || callback.emit(()));
+        let interval = Interval::new(DEFAULT_TICK_MS, move || callback.emit(()));
 
         // Responsive grid size based on screen width
         fn get_responsive_grid_size() -> (usize, usize) {
@@ -164,11 +348,27 @@ impl Component for App {
             cellules: vec![Cellule::new_dead(); cellules_width * cellules_height],
             cellules_width,
             cellules_height,
+            sparse: SparseLife::new(Some((cellules_width as isize, cellules_height as isize))),
+            use_sparse_engine: false,
+            render_mode: RenderMode::Dom,
+            canvas_ref: NodeRef::default(),
+            camera: Camera::default(),
+            show_grid_lines: true,
+            dragging_from: None,
+            drag_moved: false,
+            tick_period_ms: DEFAULT_TICK_MS,
+            queued_ticks: 0,
+            playhead_col: 0,
+            sound_enabled: false,
+            bpm: DEFAULT_BPM,
+            sequencer: None,
+            rule: Preset::default().rule(),
+            rule_preset: Preset::default(),
             _interval: interval,
         }
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::Random => {
                 self.random_mutate();
@@ -181,7 +381,7 @@ impl Component for App {
                 true
             }
             Msg::Step => {
-                self.step();
+                self.step_once();
                 true
             }
             Msg::Reset => {
@@ -195,13 +395,101 @@ impl Component for App {
                 true
             }
             Msg::ToggleCellule(idx) => {
-                let cellule = self.cellules.get_mut(idx).unwrap();
-                cellule.toggle();
+                if self.use_sparse_engine {
+                    self.sparse.toggle(self.idx_as_row_col(idx));
+                } else {
+                    let cellule = self.cellules.get_mut(idx).unwrap();
+                    cellule.toggle();
+                }
+                true
+            }
+            Msg::ToggleEngine => {
+                self.use_sparse_engine = !self.use_sparse_engine;
+                log::info!("Sparse engine: {}", self.use_sparse_engine);
+                true
+            }
+            Msg::ToggleRenderMode => {
+                self.render_mode = match self.render_mode {
+                    RenderMode::Dom => RenderMode::Canvas,
+                    RenderMode::Canvas => RenderMode::Dom,
+                };
+                self.sparse.set_bounds(self.sparse_bounds());
+                log::info!("Render mode: canvas = {}", self.render_mode == RenderMode::Canvas);
+                true
+            }
+            Msg::ToggleGridLines => {
+                self.show_grid_lines = !self.show_grid_lines;
+                true
+            }
+            Msg::PointerDown(x, y) => {
+                self.dragging_from = Some((x, y));
+                self.drag_moved = false;
+                false
+            }
+            Msg::PointerMove(x, y) => {
+                let Some((prev_x, prev_y)) = self.dragging_from else {
+                    return false;
+                };
+                let (dx, dy) = (x - prev_x, y - prev_y);
+                if dx.abs() > 2.0 || dy.abs() > 2.0 {
+                    self.drag_moved = true;
+                }
+                if let Some((cell_px_x, cell_px_y)) = self.canvas_cell_px() {
+                    self.camera.pan(-dy / cell_px_y, -dx / cell_px_x);
+                }
+                self.dragging_from = Some((x, y));
+                true
+            }
+            Msg::PointerUp(x, y) => {
+                let was_dragging = self.dragging_from.take().is_some();
+                if was_dragging && !self.drag_moved {
+                    if let Some((cell_px_x, cell_px_y)) = self.canvas_cell_px() {
+                        let (row, col) = self.camera.screen_to_board(x, y, cell_px_x, cell_px_y);
+                        self.toggle_at(row, col);
+                    }
+                }
+                true
+            }
+            Msg::CancelDrag => {
+                let was_dragging = self.dragging_from.take().is_some();
+                self.drag_moved = false;
+                was_dragging
+            }
+            Msg::Wheel(delta_y) => {
+                let factor = if delta_y > 0.0 { 0.9 } else { 1.0 / 0.9 };
+                self.camera.zoom_by(factor);
+                true
+            }
+            Msg::SetSpeed(period_ms) => {
+                self.set_tick_period(ctx, period_ms);
+                true
+            }
+            Msg::ToggleSound => {
+                self.sound_enabled = !self.sound_enabled;
+                if self.sound_enabled && self.sequencer.is_none() {
+                    match Sequencer::new() {
+                        Ok(sequencer) => self.sequencer = Some(sequencer),
+                        Err(err) => log::warn!("Failed to start AudioContext: {:?}", err),
+                    }
+                }
+                log::info!("Sound: {}", self.sound_enabled);
+                true
+            }
+            Msg::SetBpm(bpm) => {
+                let bpm = bpm.clamp(MIN_BPM, MAX_BPM);
+                self.set_tick_period(ctx, 60_000 / bpm);
+                true
+            }
+            Msg::SetRule(preset) => {
+                self.rule_preset = preset;
+                self.rule = preset.rule();
+                log::info!("Rule: {}", preset.label());
                 true
             }
             Msg::Tick => {
                 if self.active {
-                    self.step();
+                    self.queued_ticks = (self.queued_ticks + 1).min(MAX_QUEUED_TICKS);
+                    self.catch_up();
                     true
                 } else {
                     false
@@ -210,24 +498,131 @@ impl Component for App {
         }
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let cell_rows =
-            self.cellules
-                .chunks(self.cellules_width)
-                .enumerate()
-                .map(|(y, cellules)| {
-                    let idx_offset = y * self.cellules_width;
-
-                    let cells = cellules
-                        .iter()
-                        .enumerate()
-                        .map(|(x, cell)| self.view_cellule(idx_offset + x, cell, ctx.link()));
-                    html! {
-                        <div key={y} class="flex">
-                            { for cells }
-                        </div>
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if self.render_mode != RenderMode::Canvas {
+            return;
+        }
+        if let Some(canvas) = self.canvas_ref.cast::<HtmlCanvasElement>() {
+            let (visible_cols, visible_rows) = self.visible_viewport();
+            canvas_renderer::draw(
+                &canvas,
+                visible_cols,
+                visible_rows,
+                |row, col| {
+                    let (board_row, board_col) = self.camera.to_board(row, col);
+                    if self.use_sparse_engine {
+                        self.sparse.is_alive((board_row, board_col))
+                    } else {
+                        let idx = self.row_col_as_idx(board_row, board_col);
+                        self.cellules[idx].is_alive()
                     }
+                },
+                |col| self.camera.to_board(0, col).1 == self.playhead_col as isize,
+            );
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let board = if self.render_mode == RenderMode::Canvas {
+            let (visible_cols, visible_rows) = self.visible_viewport();
+            let canvas_ref = self.canvas_ref.clone();
+
+            let pointer_pos = |canvas_ref: &NodeRef, client_x: i32, client_y: i32| -> Option<(f64, f64)> {
+                let canvas = canvas_ref.cast::<HtmlCanvasElement>()?;
+                let rect = canvas.get_bounding_client_rect();
+                Some((client_x as f64 - rect.left(), client_y as f64 - rect.top()))
+            };
+
+            let ref_for_down = canvas_ref.clone();
+            let onmousedown = ctx.link().batch_callback(move |e: MouseEvent| {
+                pointer_pos(&ref_for_down, e.client_x(), e.client_y())
+                    .map(|(x, y)| Msg::PointerDown(x, y))
+            });
+            let ref_for_move = canvas_ref.clone();
+            let onmousemove = ctx.link().batch_callback(move |e: MouseEvent| {
+                if e.buttons() & 1 == 0 {
+                    return Some(Msg::CancelDrag);
+                }
+                pointer_pos(&ref_for_move, e.client_x(), e.client_y())
+                    .map(|(x, y)| Msg::PointerMove(x, y))
+            });
+            let ref_for_up = canvas_ref.clone();
+            let onmouseup = ctx.link().batch_callback(move |e: MouseEvent| {
+                pointer_pos(&ref_for_up, e.client_x(), e.client_y())
+                    .map(|(x, y)| Msg::PointerUp(x, y))
+            });
+            let onwheel = ctx.link().callback(|e: WheelEvent| {
+                e.prevent_default();
+                Msg::Wheel(e.delta_y())
+            });
+
+            // Touch equivalents of the mouse handlers above, since the app
+            // explicitly targets phone-width viewports. touchend reads from
+            // changed_touches -- touches() is already empty once the last
+            // finger lifts.
+            let ref_for_touchstart = canvas_ref.clone();
+            let ontouchstart = ctx.link().batch_callback(move |e: TouchEvent| {
+                e.prevent_default();
+                let touch = e.touches().get(0)?;
+                pointer_pos(&ref_for_touchstart, touch.client_x(), touch.client_y())
+                    .map(|(x, y)| Msg::PointerDown(x, y))
+            });
+            let ref_for_touchmove = canvas_ref.clone();
+            let ontouchmove = ctx.link().batch_callback(move |e: TouchEvent| {
+                e.prevent_default();
+                let touch = e.touches().get(0)?;
+                pointer_pos(&ref_for_touchmove, touch.client_x(), touch.client_y())
+                    .map(|(x, y)| Msg::PointerMove(x, y))
+            });
+            let ref_for_touchend = canvas_ref.clone();
+            let ontouchend = ctx.link().batch_callback(move |e: TouchEvent| {
+                e.prevent_default();
+                let touch = e.changed_touches().get(0)?;
+                pointer_pos(&ref_for_touchend, touch.client_x(), touch.client_y())
+                    .map(|(x, y)| Msg::PointerUp(x, y))
+            });
+
+            html! {
+                <canvas
+                    ref={self.canvas_ref.clone()}
+                    width={visible_cols.to_string()}
+                    height={visible_rows.to_string()}
+                    style="image-rendering: pixelated; width: 100%; max-width: 640px; height: auto; cursor: grab; touch-action: none;"
+                    {onmousedown}
+                    {onmousemove}
+                    {onmouseup}
+                    {onwheel}
+                    {ontouchstart}
+                    {ontouchmove}
+                    {ontouchend}
+                />
+            }
+        } else {
+            let cell_rows = (0..self.cellules_height).map(|y| {
+                let idx_offset = y * self.cellules_width;
+
+                let cells = (0..self.cellules_width).map(|x| {
+                    let idx = idx_offset + x;
+                    let alive = if self.use_sparse_engine {
+                        self.sparse.is_alive((y as isize, x as isize))
+                    } else {
+                        self.cellules[idx].is_alive()
+                    };
+                    self.view_cellule_at(idx, alive, x == self.playhead_col, ctx.link())
                 });
+                html! {
+                    <div key={y} class="flex">
+                        { for cells }
+                    </div>
+                }
+            });
+
+            html! {
+                <>
+                    { for cell_rows }
+                </>
+            }
+        };
 
         let play_button = if self.active {
             html! {
@@ -274,7 +669,7 @@ impl Component for App {
                         <div class="flex justify-center mb-4 sm:mb-8">
                             <div class="overflow-x-auto">
                                 <div class="inline-block border border-gray-300 rounded-md p-1 bg-gray-50">
-                                    { for cell_rows }
+                                    { board }
                                 </div>
                             </div>
                         </div>
@@ -312,17 +707,110 @@ impl Component for App {
                                 </svg>
                                 { "Reset" }
                             </button>
+
+                            <button
+                                class="flex items-center justify-center bg-teal-600 hover:bg-teal-700 text-white font-semibold py-2 px-4 rounded-lg shadow-md transition-colors duration-300"
+                                onclick={ctx.link().callback(|_| Msg::ToggleEngine)}
+                            >
+                                { if self.use_sparse_engine { "Sparse engine" } else { "Dense engine" } }
+                            </button>
+
+                            <button
+                                class="flex items-center justify-center bg-cyan-600 hover:bg-cyan-700 text-white font-semibold py-2 px-4 rounded-lg shadow-md transition-colors duration-300"
+                                onclick={ctx.link().callback(|_| Msg::ToggleRenderMode)}
+                            >
+                                { if self.render_mode == RenderMode::Canvas { "Canvas renderer" } else { "DOM renderer" } }
+                            </button>
+
+                            <button
+                                class="flex items-center justify-center bg-amber-600 hover:bg-amber-700 text-white font-semibold py-2 px-4 rounded-lg shadow-md transition-colors duration-300"
+                                onclick={ctx.link().callback(|_| Msg::ToggleGridLines)}
+                            >
+                                { if self.show_grid_lines { "Hide grid lines" } else { "Show grid lines" } }
+                            </button>
+
+                            <div class="flex items-center gap-2 bg-gray-100 rounded-lg px-3 py-2">
+                                <button
+                                    class="font-bold text-gray-700 hover:text-gray-900 w-6"
+                                    onclick={ctx.link().callback({
+                                        let period = self.tick_period_ms;
+                                        move |_| Msg::SetSpeed(period + SPEED_STEP_MS)
+                                    })}
+                                >
+                                    { "-" }
+                                </button>
+                                <span class="text-gray-700 text-sm w-16 text-center">
+                                    { format!("{} ms", self.tick_period_ms) }
+                                </span>
+                                <button
+                                    class="font-bold text-gray-700 hover:text-gray-900 w-6"
+                                    onclick={ctx.link().callback({
+                                        let period = self.tick_period_ms;
+                                        move |_| Msg::SetSpeed(period.saturating_sub(SPEED_STEP_MS))
+                                    })}
+                                >
+                                    { "+" }
+                                </button>
+                            </div>
+
+                            <button
+                                class="flex items-center justify-center bg-rose-600 hover:bg-rose-700 text-white font-semibold py-2 px-4 rounded-lg shadow-md transition-colors duration-300"
+                                onclick={ctx.link().callback(|_| Msg::ToggleSound)}
+                            >
+                                { if self.sound_enabled { "Sound: on" } else { "Sound: off" } }
+                            </button>
+
+                            <div class="flex items-center gap-2 bg-gray-100 rounded-lg px-3 py-2">
+                                <button
+                                    class="font-bold text-gray-700 hover:text-gray-900 w-6"
+                                    onclick={ctx.link().callback({
+                                        let bpm = self.bpm;
+                                        move |_| Msg::SetBpm(bpm.saturating_sub(BPM_STEP))
+                                    })}
+                                >
+                                    { "-" }
+                                </button>
+                                <span class="text-gray-700 text-sm w-20 text-center">
+                                    { format!("{} bpm", self.bpm) }
+                                </span>
+                                <button
+                                    class="font-bold text-gray-700 hover:text-gray-900 w-6"
+                                    onclick={ctx.link().callback({
+                                        let bpm = self.bpm;
+                                        move |_| Msg::SetBpm(bpm + BPM_STEP)
+                                    })}
+                                >
+                                    { "+" }
+                                </button>
+                            </div>
+                        </div>
+
+                        <div class="flex flex-wrap justify-center gap-2 sm:gap-3 mt-3">
+                            { for Preset::ALL.iter().map(|&preset| {
+                                let selected = preset == self.rule_preset;
+                                let class = if selected {
+                                    "bg-indigo-900 text-white font-semibold py-1 px-3 rounded-lg text-sm"
+                                } else {
+                                    "bg-gray-200 hover:bg-gray-300 text-gray-800 font-semibold py-1 px-3 rounded-lg text-sm"
+                                };
+                                html! {
+                                    <button
+                                        key={preset.label()}
+                                        class={class}
+                                        onclick={ctx.link().callback(move |_| Msg::SetRule(preset))}
+                                    >
+                                        { preset.label() }
+                                    </button>
+                                }
+                            }) }
                         </div>
                     </div>
-                    
+
                     <div class="bg-white rounded-xl shadow-lg p-2 sm:p-6">
-                        <h2 class="text-lg sm:text-xl font-semibold text-gray-800 mb-2 sm:mb-4">{ "Rules of Conway's Game of Life" }</h2>
-                        <ul class="list-disc list-inside space-y-1 sm:space-y-2 text-gray-700 text-sm sm:text-base">
-                            <li>{ "Any live cell with fewer than two live neighbors dies (underpopulation)" }</li>
-                            <li>{ "Any live cell with two or three live neighbors survives" }</li>
-                            <li>{ "Any live cell with more than three live neighbors dies (overpopulation)" }</li>
-                            <li>{ "Any dead cell with exactly three live neighbors becomes alive (reproduction)" }</li>
-                        </ul>
+                        <h2 class="text-lg sm:text-xl font-semibold text-gray-800 mb-2 sm:mb-4">{ "Rule" }</h2>
+                        <p class="text-gray-700 text-sm sm:text-base">
+                            { format!("Currently playing {} -- a dead cell is born, or a live cell survives, exactly when its live-neighbor count matches the B (birth) or S (survival) digits in its notation.", self.rule_preset.label()) }
+                        </p>
                     </div>
                 </main>
                 
@@ -334,15 +822,15 @@ impl Component for App {
     }
 }
 
+fn now_ms() -> f64 {
+    window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or(0.0)
+}
+
 fn wrap(coord: isize, range: isize) -> usize {
-    let result = if coord < 0 {
-        coord + range
-    } else if coord >= range {
-        coord - range
-    } else {
-        coord
-    };
-    result as usize
+    coord.rem_euclid(range) as usize
 }
 
 fn main() {
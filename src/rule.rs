@@ -0,0 +1,76 @@
+// A dead cell is born iff its live-neighbor count is in `birth`; a live
+// cell survives iff its live-neighbor count is in `survive`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rule {
+    birth: [bool; 9],
+    survive: [bool; 9],
+}
+
+impl Rule {
+    pub fn new(birth_counts: &[usize], survive_counts: &[usize]) -> Self {
+        let mut birth = [false; 9];
+        let mut survive = [false; 9];
+        for &n in birth_counts {
+            birth[n] = true;
+        }
+        for &n in survive_counts {
+            survive[n] = true;
+        }
+        Self { birth, survive }
+    }
+
+    pub fn births(&self, live_neighbors: usize) -> bool {
+        self.birth[live_neighbors]
+    }
+
+    pub fn survives(&self, live_neighbors: usize) -> bool {
+        self.survive[live_neighbors]
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Preset::default().rule()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Preset {
+    Conway,
+    HighLife,
+    Replicator,
+    Seeds,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 4] = [
+        Preset::Conway,
+        Preset::HighLife,
+        Preset::Replicator,
+        Preset::Seeds,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Preset::Conway => "B3/S23 (Conway)",
+            Preset::HighLife => "B36/S23 (HighLife)",
+            Preset::Replicator => "B1357/S1357 (Replicator)",
+            Preset::Seeds => "B2/S (Seeds)",
+        }
+    }
+
+    pub fn rule(self) -> Rule {
+        match self {
+            Preset::Conway => Rule::new(&[3], &[2, 3]),
+            Preset::HighLife => Rule::new(&[3, 6], &[2, 3]),
+            Preset::Replicator => Rule::new(&[1, 3, 5, 7], &[1, 3, 5, 7]),
+            Preset::Seeds => Rule::new(&[2], &[]),
+        }
+    }
+}
+
+impl Default for Preset {
+    fn default() -> Self {
+        Preset::Conway
+    }
+}
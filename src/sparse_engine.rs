@@ -0,0 +1,97 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::rule::Rule;
+
+pub type Coord = (isize, isize);
+
+// Only live cells are stored, so the cost of `step` scales with the live
+// population rather than `width * height`.
+pub struct SparseLife {
+    live: HashSet<Coord>,
+    // Some((width, height)) wraps coordinates toroidally; None is unbounded.
+    bounds: Option<(isize, isize)>,
+}
+
+impl SparseLife {
+    pub fn new(bounds: Option<(isize, isize)>) -> Self {
+        Self {
+            live: HashSet::new(),
+            bounds,
+        }
+    }
+
+    pub fn live_cells(&self) -> &HashSet<Coord> {
+        &self.live
+    }
+
+    pub fn is_alive(&self, coord: Coord) -> bool {
+        self.live.contains(&self.normalize(coord))
+    }
+
+    pub fn set_alive(&mut self, coord: Coord) {
+        self.live.insert(self.normalize(coord));
+    }
+
+    pub fn set_dead(&mut self, coord: Coord) {
+        self.live.remove(&self.normalize(coord));
+    }
+
+    pub fn toggle(&mut self, coord: Coord) {
+        let coord = self.normalize(coord);
+        if !self.live.remove(&coord) {
+            self.live.insert(coord);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.live.clear();
+    }
+
+    // Renormalizes existing live cells so switching back to a bounded plane
+    // doesn't leave stale out-of-range coordinates behind.
+    pub fn set_bounds(&mut self, bounds: Option<(isize, isize)>) {
+        self.bounds = bounds;
+        if bounds.is_some() {
+            let cells: Vec<Coord> = self.live.drain().collect();
+            self.live = cells.into_iter().map(|c| self.normalize(c)).collect();
+        }
+    }
+
+    fn normalize(&self, (row, col): Coord) -> Coord {
+        match self.bounds {
+            Some((width, height)) => (row.rem_euclid(height), col.rem_euclid(width)),
+            None => (row, col),
+        }
+    }
+
+    pub fn step(&mut self, rule: &Rule) {
+        let mut neighbor_counts: HashMap<Coord, u8> = HashMap::new();
+        for &(row, col) in &self.live {
+            for d_row in -1..=1 {
+                for d_col in -1..=1 {
+                    if d_row == 0 && d_col == 0 {
+                        continue;
+                    }
+                    let neighbor = self.normalize((row + d_row, col + d_col));
+                    *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+                }
+            }
+        }
+        // A live cell with no live neighbors gets no increment above, but
+        // still needs a count-0 entry so rules whose survive set includes
+        // 0 (e.g. life-without-death variants) don't silently kill it.
+        for &coord in &self.live {
+            neighbor_counts.entry(coord).or_insert(0);
+        }
+
+        let mut next = HashSet::with_capacity(self.live.len());
+        for (coord, count) in neighbor_counts {
+            let was_alive = self.live.contains(&coord);
+            let count = count as usize;
+            if (was_alive && rule.survives(count)) || (!was_alive && rule.births(count)) {
+                next.insert(coord);
+            }
+        }
+        self.live = next;
+    }
+}